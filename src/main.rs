@@ -1,16 +1,15 @@
-use crossterm::{
-    self,
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
+mod term_backend;
+
+use serde::{Deserialize, Serialize};
 use std::{
-    io,
+    fs, io,
+    path::PathBuf,
     time::{Duration, Instant},
 };
+use term_backend::{ActiveTermBackend, Key, MouseKind, TermBackend, TermEvent};
 use tui::{
     self,
-    backend::{Backend, CrosstermBackend},
+    backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
@@ -18,119 +17,451 @@ use tui::{
     Frame, Terminal,
 };
 
-struct StatefulList<T> {
-    list_state: ListState,
-    items: Vec<T>,
+#[cfg(feature = "crossterm")]
+type TuiBackend = tui::backend::CrosstermBackend<io::Stdout>;
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+type TuiBackend = tui::backend::TermionBackend<io::Stdout>;
+
+#[derive(PartialEq)]
+enum Mode {
+    Add,
+    Update,
+    Normal,
 }
 
-impl<T> StatefulList<T> {
-    fn with_items(items: Vec<T>) -> StatefulList<T> {
-        StatefulList {
-            list_state: ListState::default(),
-            items,
-        }
-    }
+#[derive(Clone, Copy, PartialEq)]
+enum Tab {
+    Open,
+    Done,
+}
 
-    fn next(&mut self) {
-        let i = match self.list_state.selected() {
-            Some(i) => {
-                if i >= self.items.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
-        };
+#[derive(Clone, Serialize, Deserialize)]
+struct TodoItem {
+    text: String,
+    done: bool,
+}
 
-        self.list_state.select(Some(i))
+impl TodoItem {
+    fn new(text: String) -> TodoItem {
+        TodoItem { text, done: false }
     }
+}
 
-    fn previous(&mut self) {
-        let i = match self.list_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.items.len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
-        };
+fn todos_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("todo_list")
+}
 
-        self.list_state.select(Some(i))
-    }
+fn todos_file() -> PathBuf {
+    todos_dir().join("todos.json")
+}
 
-    fn unselect(&mut self) {
-        self.list_state.select(None);
+fn load_todos() -> Vec<TodoItem> {
+    fs::read_to_string(todos_file())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_else(|| {
+            vec![
+                TodoItem::new(String::from("Be a gangster")),
+                TodoItem::new(String::from("Finish a project")),
+                TodoItem::new(String::from("Be a coder")),
+            ]
+        })
+}
+
+fn save_todos(items: &[TodoItem]) -> io::Result<()> {
+    fs::create_dir_all(todos_dir())?;
+    let contents = serde_json::to_string_pretty(items)?;
+    fs::write(todos_file(), contents)
+}
+
+#[cfg(feature = "crossterm")]
+struct LinkSpan {
+    row: u16,
+    col_start: u16,
+    label: String,
+    target: String,
+}
+
+/// Hostname component for `file://` URIs, resolved once and cached: from
+/// `$HOSTNAME` if set, otherwise by shelling out to `hostname`.
+#[cfg(feature = "crossterm")]
+fn local_hostname() -> &'static str {
+    static HOSTNAME: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+    HOSTNAME.get_or_init(|| {
+        std::env::var("HOSTNAME")
+            .ok()
+            .or_else(|| {
+                std::process::Command::new("hostname")
+                    .output()
+                    .ok()
+                    .and_then(|output| String::from_utf8(output.stdout).ok())
+            })
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    })
+}
+
+/// Finds a clickable-looking substring in an item's text: an `https://` URL,
+/// else an `http://` URL, else the first path token that names a file that
+/// actually exists on disk. URLs win over paths regardless of where they
+/// appear in the text.
+#[cfg(feature = "crossterm")]
+fn detect_link(text: &str) -> Option<(usize, String, String)> {
+    for prefix in ["https://", "http://"] {
+        if let Some(start) = text.find(prefix) {
+            let end = text[start..]
+                .find(char::is_whitespace)
+                .map(|i| start + i)
+                .unwrap_or(text.len());
+            let label = text[start..end].to_string();
+            return Some((start, label.clone(), label));
+        }
+    }
+    for token in text.split_whitespace() {
+        if token.starts_with('/') || token.starts_with("~/") || token.starts_with("./") {
+            let candidate = match token.strip_prefix("~/") {
+                Some(rest) => format!("{}/{}", std::env::var("HOME").unwrap_or_default(), rest),
+                None => token.to_string(),
+            };
+            let Ok(abs_path) = fs::canonicalize(&candidate) else {
+                continue;
+            };
+            let start = text.find(token)?;
+            let target = format!("file://{}{}", local_hostname(), abs_path.display());
+            return Some((start, token.to_string(), target));
+        }
     }
+    None
 }
 
-#[derive(PartialEq)]
-enum Mode {
-    Add,
-    Delete,
-    Update,
-    Normal,
+/// Some editor-embedded terminals advertise `$TERM_PROGRAM` but mishandle
+/// OSC 8 hyperlink sequences, so hyperlinks are skipped there.
+#[cfg(feature = "crossterm")]
+fn hyperlinks_enabled() -> bool {
+    !matches!(
+        std::env::var("TERM_PROGRAM").as_deref(),
+        Ok("vscode") | Ok("zed")
+    )
 }
 
-struct AppState<'a> {
-    list: StatefulList<&'a mut String>,
+struct AppState {
+    items: Vec<TodoItem>,
+    open_state: ListState,
+    done_state: ListState,
+    tab: Tab,
     mode: Mode,
+    input: String,
+    list_rect: Rect,
+    tabs_rect: Rect,
+    hints_rect: Rect,
+    #[cfg(feature = "crossterm")]
+    link_spans: Vec<LinkSpan>,
+    list_offset: usize,
+    #[cfg(feature = "crossterm")]
+    list_selected: Option<usize>,
 }
 
-impl<'a> AppState<'a> {
-    fn new(list_vector: Vec<&'a mut String>) -> AppState<'a> {
+impl AppState {
+    fn new(items: Vec<TodoItem>) -> AppState {
         AppState {
-            list: StatefulList::with_items(list_vector),
+            items,
+            open_state: ListState::default(),
+            done_state: ListState::default(),
+            tab: Tab::Open,
             mode: Mode::Normal,
+            input: String::new(),
+            list_rect: Rect::default(),
+            tabs_rect: Rect::default(),
+            hints_rect: Rect::default(),
+            #[cfg(feature = "crossterm")]
+            link_spans: Vec::new(),
+            list_offset: 0,
+            #[cfg(feature = "crossterm")]
+            list_selected: None,
+        }
+    }
+
+    fn current_state(&mut self) -> &mut ListState {
+        match self.tab {
+            Tab::Open => &mut self.open_state,
+            Tab::Done => &mut self.done_state,
+        }
+    }
+
+    fn filtered_indices(&self) -> Vec<usize> {
+        let want_done = self.tab == Tab::Done;
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.done == want_done)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn selected_item_index(&mut self) -> Option<usize> {
+        let indices = self.filtered_indices();
+        let selected = self.current_state().selected()?;
+        indices.get(selected).copied()
+    }
+
+    fn next(&mut self) {
+        let len = self.filtered_indices().len();
+        let state = self.current_state();
+        if len == 0 {
+            state.select(None);
+            return;
+        }
+        let i = match state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        state.select(Some(i));
+    }
+
+    fn previous(&mut self) {
+        let len = self.filtered_indices().len();
+        let state = self.current_state();
+        if len == 0 {
+            state.select(None);
+            return;
+        }
+        let i = match state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        state.select(Some(i));
+    }
+
+    fn unselect(&mut self) {
+        self.current_state().select(None);
+    }
+
+    fn remove_selected(&mut self) {
+        if let Some(idx) = self.selected_item_index() {
+            self.items.remove(idx);
+            let len = self.filtered_indices().len();
+            let state = self.current_state();
+            match state.selected() {
+                Some(i) if len == 0 => {
+                    let _ = i;
+                    state.select(None);
+                }
+                Some(i) if i >= len => state.select(Some(len - 1)),
+                _ => {}
+            }
+        }
+    }
+
+    fn toggle_selected(&mut self) {
+        if let Some(idx) = self.selected_item_index() {
+            self.items[idx].done = !self.items[idx].done;
+            self.current_state().select(None);
         }
     }
+
+    fn next_tab(&mut self) {
+        self.tab = match self.tab {
+            Tab::Open => Tab::Done,
+            Tab::Done => Tab::Open,
+        };
+    }
+
+    fn previous_tab(&mut self) {
+        self.next_tab();
+    }
 }
 
-fn run_app<B: Backend>(
+fn run_app<B: Backend, T: TermBackend>(
     terminal: &mut Terminal<B>,
+    term: &mut T,
     mut app: AppState,
     tick_rate: Duration,
 ) -> Result<(), io::Error> {
     let mut last_tick = Instant::now();
     loop {
         terminal.draw(|f| ui(f, &mut app))?;
+        emit_hyperlinks(&app)?;
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
 
-        if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
+        match term.poll_event(timeout)? {
+            Some(TermEvent::Mouse(mouse)) => {
+                match mouse.kind {
+                    MouseKind::ScrollDown => app.next(),
+                    MouseKind::ScrollUp => app.previous(),
+                    MouseKind::LeftDown => {
+                        if point_in_rect(app.list_rect, mouse.column, mouse.row) {
+                            if let Some(visible_idx) = get_item_position(app.list_rect, mouse.row) {
+                                let idx = visible_idx + app.list_offset;
+                                if idx < app.filtered_indices().len() {
+                                    app.current_state().select(Some(idx));
+                                }
+                            }
+                        } else if point_in_rect(app.tabs_rect, mouse.column, mouse.row) {
+                            app.next_tab();
+                        } else if point_in_rect(app.hints_rect, mouse.column, mouse.row) {
+                            let width = app.hints_rect.width.max(1);
+                            let rel = mouse.column.saturating_sub(app.hints_rect.x);
+                            if rel < width / 3 {
+                                app.mode = Mode::Add;
+                            } else if rel < (width * 2) / 3 {
+                                app.remove_selected();
+                                let _ = save_todos(&app.items);
+                            } else {
+                                app.mode = Mode::Update;
+                            }
+                        }
+                    }
+                    MouseKind::Other => {}
+                }
+            }
+            Some(TermEvent::Key(key)) => {
                 match app.mode {
-                    Mode::Update => match key.code {
-                        KeyCode::Char(c) => {
-                            app.list.items[app.list.list_state.selected().unwrap()].push(c);
+                    Mode::Update => match key {
+                        Key::Char(c) => {
+                            if let Some(idx) = app.selected_item_index() {
+                                app.items[idx].text.push(c);
+                            }
+                        }
+                        Key::Backspace => {}
+                        Key::Enter => {
+                            app.mode = Mode::Normal;
+                            let _ = save_todos(&app.items);
                         }
-                        KeyCode::Backspace => {}
-                        KeyCode::Enter => app.mode = Mode::Normal,
                         _ => {}
                     },
-                    Mode::Add => {}
-                    Mode::Normal => match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        KeyCode::Left => app.list.unselect(),
-                        KeyCode::Down => app.list.next(),
-                        KeyCode::Up => app.list.previous(),
-                        KeyCode::Char('a') => app.mode = Mode::Add,
-                        KeyCode::Char('e') => app.mode = Mode::Update,
+                    Mode::Add => match key {
+                        Key::Char(c) => app.input.push(c),
+                        Key::Backspace => {
+                            app.input.pop();
+                        }
+                        Key::Enter => {
+                            app.items.push(TodoItem::new(app.input.clone()));
+                            app.input.clear();
+                            app.mode = Mode::Normal;
+                            let _ = save_todos(&app.items);
+                        }
+                        Key::Esc => {
+                            app.input.clear();
+                            app.mode = Mode::Normal;
+                        }
+                        _ => {}
+                    },
+                    Mode::Normal => match key {
+                        Key::Char('q') => {
+                            let _ = save_todos(&app.items);
+                            return Ok(());
+                        }
+                        Key::Left => app.unselect(),
+                        Key::Down => app.next(),
+                        Key::Up => app.previous(),
+                        Key::Char('a') => app.mode = Mode::Add,
+                        Key::Char('e') => app.mode = Mode::Update,
+                        Key::Char('x') => {
+                            app.remove_selected();
+                            let _ = save_todos(&app.items);
+                        }
+                        Key::Char(' ') => {
+                            app.toggle_selected();
+                            let _ = save_todos(&app.items);
+                        }
+                        Key::Tab => app.next_tab(),
+                        Key::BackTab => app.previous_tab(),
                         _ => {}
                     },
-                    Mode::Delete => {}
                 }
-                if app.mode == Mode::Normal {}
             }
+            _ => {}
         }
         if last_tick.elapsed() >= tick_rate {
             last_tick = Instant::now();
         }
     }
 }
+/// Mirrors `List`'s internal scroll bookkeeping (tui 0.19's `ListState`
+/// doesn't expose its offset) so hyperlink positions can be computed for
+/// the same visible window the widget actually renders. All rows are a
+/// single line tall here, so item height is always 1.
+fn list_scroll_offset(prev_offset: usize, selected: Option<usize>, len: usize, height: usize) -> usize {
+    if len == 0 || height == 0 {
+        return 0;
+    }
+    let mut start = prev_offset.min(len - 1);
+    let mut end = start;
+    let mut used = 0;
+    while end < len && used < height {
+        used += 1;
+        end += 1;
+    }
+
+    let selected = selected.unwrap_or(0).min(len - 1);
+    while selected >= end {
+        used += 1;
+        end += 1;
+        while used > height {
+            used -= 1;
+            start += 1;
+        }
+    }
+    while selected < start {
+        start -= 1;
+        used += 1;
+        while used > height {
+            used -= 1;
+        }
+    }
+    start
+}
+
+/// Builds the `List`'s rows, detecting hyperlink-able spans in each item's
+/// text along the way; the termion backend has no hyperlink support, so it
+/// skips link detection entirely rather than computing spans nobody reads.
+#[cfg(feature = "crossterm")]
+fn build_list_items(app: &AppState, indices: &[usize]) -> (Vec<ListItem<'static>>, Vec<LinkSpan>) {
+    let mut link_spans = Vec::new();
+    let list_items = indices
+        .iter()
+        .enumerate()
+        .map(|(row, &idx)| {
+            let item = &app.items[idx];
+            let style = if item.done {
+                Style::default().add_modifier(Modifier::CROSSED_OUT | Modifier::DIM)
+            } else {
+                Style::default()
+            };
+            if let Some((col_start, label, target)) = detect_link(&item.text) {
+                link_spans.push(LinkSpan {
+                    row: row as u16,
+                    col_start: col_start as u16,
+                    label,
+                    target,
+                });
+            }
+            ListItem::new(Spans::from(Span::styled(item.text.clone(), style)))
+        })
+        .collect();
+    (list_items, link_spans)
+}
+
+#[cfg(not(feature = "crossterm"))]
+fn build_list_items(app: &AppState, indices: &[usize]) -> Vec<ListItem<'static>> {
+    indices
+        .iter()
+        .map(|&idx| {
+            let item = &app.items[idx];
+            let style = if item.done {
+                Style::default().add_modifier(Modifier::CROSSED_OUT | Modifier::DIM)
+            } else {
+                Style::default()
+            };
+            ListItem::new(Spans::from(Span::styled(item.text.clone(), style)))
+        })
+        .collect()
+}
+
 fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -143,21 +474,15 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
 
     let options_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Min(2)])
+        .constraints([Constraint::Length(20), Constraint::Min(2)])
         .split(chunks[2]);
 
     let block = Block::default().borders(Borders::ALL);
-    let list_items: Vec<ListItem> = app
-        .list
-        .items
-        .iter()
-        .map(|i| {
-            ListItem::new(Spans::from(Span::styled(
-                String::from(&*i.as_str()),
-                Style::default(),
-            )))
-        })
-        .collect();
+    let indices = app.filtered_indices();
+    #[cfg(feature = "crossterm")]
+    let (list_items, link_spans) = build_list_items(app, &indices);
+    #[cfg(not(feature = "crossterm"))]
+    let list_items = build_list_items(app, &indices);
 
     let list = List::new(list_items)
         .block(block)
@@ -168,65 +493,166 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &mut AppState) {
         )
         .highlight_symbol(">>");
 
+    let list_selected = app.current_state().selected();
+    let inner_height = chunks[1].height.saturating_sub(2) as usize;
+    app.list_offset = list_scroll_offset(app.list_offset, list_selected, indices.len(), inner_height);
+
+    let header_text = match app.mode {
+        Mode::Add => app.input.as_str(),
+        _ => "Todo List",
+    };
+    let header_alignment = match app.mode {
+        Mode::Add => Alignment::Left,
+        Mode::Update | Mode::Normal => Alignment::Center,
+    };
     let app_title = Paragraph::new(vec![Spans::from(Span::styled(
-        "Todo List",
+        header_text,
         Style::default(),
     ))])
     .block(Block::default().borders(Borders::ALL))
     .style(Style::default())
-    .alignment(Alignment::Center);
+    .alignment(header_alignment);
 
-    let options = vec!["Add -> A", "Delete -> X", "Edit -> E"];
-
-    let options: Vec<Spans> = options
-        .iter()
-        .map(|i| Spans::from(Span::styled(*i, Style::default())))
-        .collect();
+    let tabs = Tabs::new(vec![Spans::from("Open"), Spans::from("Done")])
+        .select(match app.tab {
+            Tab::Open => 0,
+            Tab::Done => 1,
+        })
+        .block(Block::default().borders(Borders::ALL))
+        .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
 
-    let options_list = Tabs::new(options)
+    let hints = "Add -> A   Delete -> X   Edit -> E   Toggle -> Space   Switch tab -> Tab";
+    let hints_widget = Paragraph::new(Spans::from(hints))
         .block(Block::default().borders(Borders::ALL))
         .style(Style::default());
 
     match app.mode {
         Mode::Add => {
-            f.set_cursor(chunks[0].x, chunks[0].y);
+            f.set_cursor(
+                chunks[0].x + 1 + app.input.chars().count() as u16,
+                chunks[0].y + 1,
+            );
         }
         Mode::Update => {}
-        Mode::Delete => {}
         Mode::Normal => {}
     }
 
     f.render_widget(app_title, chunks[0]);
-    f.render_widget(options_list, options_chunks[0]);
-    f.render_stateful_widget(list, chunks[1], &mut app.list.list_state);
+    f.render_widget(tabs, options_chunks[0]);
+    f.render_widget(hints_widget, options_chunks[1]);
+    f.render_stateful_widget(list, chunks[1], app.current_state());
+
+    #[cfg(feature = "crossterm")]
+    {
+        app.list_selected = list_selected;
+        app.link_spans = link_spans;
+    }
+    app.list_rect = chunks[1];
+    app.tabs_rect = options_chunks[0];
+    app.hints_rect = options_chunks[1];
+}
+
+/// tui's buffer diffing strips raw escape bytes from styled spans, so
+/// hyperlinks are written directly to the terminal in a pass after each
+/// draw instead of going through a `Span`.
+#[cfg(feature = "crossterm")]
+fn emit_hyperlinks(app: &AppState) -> io::Result<()> {
+    use crossterm::{cursor::MoveTo, queue};
+    use std::io::Write;
+
+    if app.link_spans.is_empty() || !hyperlinks_enabled() {
+        return Ok(());
+    }
+
+    let mut stdout = io::stdout();
+    let last_row = app.list_rect.y + app.list_rect.height.saturating_sub(1);
+    for link in &app.link_spans {
+        let filtered_row = link.row as usize;
+        if filtered_row < app.list_offset {
+            continue;
+        }
+        let visible_row = (filtered_row - app.list_offset) as u16;
+        let row = app.list_rect.y + 1 + visible_row;
+        let highlight_shift = if app.list_selected.is_some() { 2 } else { 0 };
+        let col = app.list_rect.x + 1 + highlight_shift + link.col_start;
+        if row >= last_row {
+            continue;
+        }
+        queue!(stdout, MoveTo(col, row))?;
+        write!(
+            stdout,
+            "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
+            link.target, link.label
+        )?;
+    }
+    stdout.flush()
+}
+
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+fn emit_hyperlinks(_app: &AppState) -> io::Result<()> {
+    Ok(())
+}
+
+fn point_in_rect(rect: Rect, column: u16, row: u16) -> bool {
+    column >= rect.x
+        && column < rect.x + rect.width
+        && row >= rect.y
+        && row < rect.y + rect.height
+}
+
+/// Maps a screen row inside a bordered, one-line-per-item list `Rect` to the
+/// zero-based position of that row within the visible window (the list's
+/// scroll offset is not added), or `None` if the row falls on a border.
+fn get_item_position(chunk: Rect, row: u16) -> Option<usize> {
+    if chunk.height <= 2 || row <= chunk.y || row >= chunk.y + chunk.height - 1 {
+        return None;
+    }
+    Some((row - chunk.y - 1) as usize)
+}
+
+#[cfg(feature = "crossterm")]
+fn restore_terminal() {
+    use crossterm::{
+        cursor, event::DisableMouseCapture, execute, terminal::disable_raw_mode,
+        terminal::LeaveAlternateScreen,
+    };
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, cursor::Show);
+}
+
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+fn restore_terminal() {
+    use std::io::Write;
+    let _ = write!(io::stdout(), "{}", termion::screen::ToMainScreen);
+    let _ = io::stdout().flush();
 }
 
-fn get_item_position(list: Vec<ListItem>, index: usize, chunk: Rect) {
-    // TODO
+fn install_panic_hook() {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        restore_terminal();
+        original_hook(panic_info);
+    }));
 }
 
 fn main() -> Result<(), io::Error> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+    install_panic_hook();
+
+    let mut term = ActiveTermBackend::init()?;
+
+    #[cfg(feature = "crossterm")]
+    let backend: TuiBackend = tui::backend::CrosstermBackend::new(io::stdout());
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    let backend: TuiBackend = tui::backend::TermionBackend::new(io::stdout());
+
     let mut terminal = Terminal::new(backend)?;
 
-    let s1: &mut String = &mut String::from("Be a gangster");
-    let s2: &mut String = &mut String::from("Finish a project");
-    let s3: &mut String = &mut String::from("Be a coder");
-    let mut list_vector: Vec<&mut String> = vec![s1, s2, s3];
-    let app = AppState::new(list_vector);
+    let app = AppState::new(load_todos());
     let tick_rate = Duration::from_millis(250);
 
-    let result = run_app(&mut terminal, app, tick_rate);
+    let result = run_app(&mut terminal, &mut term, app, tick_rate);
     // restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    term.restore()?;
     terminal.show_cursor()?;
 
     result
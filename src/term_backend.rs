@@ -0,0 +1,209 @@
+//! Abstraction over the terminal I/O layer so the event loop does not depend
+//! directly on crossterm. Selecting the `crossterm` or `termion` Cargo
+//! feature picks the implementation compiled into `ActiveTermBackend`; when
+//! both are enabled crossterm is used, and since crossterm is a default
+//! feature, termion is only picked with `--no-default-features`.
+use std::io;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Char(char),
+    Backspace,
+    Enter,
+    Esc,
+    Left,
+    Right,
+    Up,
+    Down,
+    Tab,
+    BackTab,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseKind {
+    LeftDown,
+    ScrollUp,
+    ScrollDown,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MouseInfo {
+    pub kind: MouseKind,
+    pub column: u16,
+    pub row: u16,
+}
+
+pub enum TermEvent {
+    Key(Key),
+    Mouse(MouseInfo),
+    Tick,
+}
+
+/// Knows how to put the terminal into raw/alternate-screen mode, read the
+/// next input event, and restore the terminal to its original state.
+pub trait TermBackend {
+    fn init() -> io::Result<Self>
+    where
+        Self: Sized;
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TermEvent>>;
+
+    fn restore(&mut self) -> io::Result<()>;
+}
+
+#[cfg(feature = "crossterm")]
+mod crossterm_backend {
+    use super::{Key, MouseInfo, MouseKind, TermBackend, TermEvent};
+    use crossterm::{
+        cursor,
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseButton,
+            MouseEventKind,
+        },
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use std::io;
+    use std::time::Duration;
+
+    pub struct CrosstermTermBackend;
+
+    impl TermBackend for CrosstermTermBackend {
+        fn init() -> io::Result<Self> {
+            enable_raw_mode()?;
+            execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+            Ok(CrosstermTermBackend)
+        }
+
+        fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TermEvent>> {
+            if !event::poll(timeout)? {
+                return Ok(None);
+            }
+            match event::read()? {
+                Event::Key(key) => Ok(Some(TermEvent::Key(match key.code {
+                    KeyCode::Char(c) => Key::Char(c),
+                    KeyCode::Backspace => Key::Backspace,
+                    KeyCode::Enter => Key::Enter,
+                    KeyCode::Esc => Key::Esc,
+                    KeyCode::Left => Key::Left,
+                    KeyCode::Right => Key::Right,
+                    KeyCode::Up => Key::Up,
+                    KeyCode::Down => Key::Down,
+                    KeyCode::Tab => Key::Tab,
+                    KeyCode::BackTab => Key::BackTab,
+                    _ => Key::Other,
+                }))),
+                Event::Mouse(mouse) => Ok(Some(TermEvent::Mouse(MouseInfo {
+                    kind: match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => MouseKind::LeftDown,
+                        MouseEventKind::ScrollUp => MouseKind::ScrollUp,
+                        MouseEventKind::ScrollDown => MouseKind::ScrollDown,
+                        _ => MouseKind::Other,
+                    },
+                    column: mouse.column,
+                    row: mouse.row,
+                }))),
+                _ => Ok(Some(TermEvent::Tick)),
+            }
+        }
+
+        fn restore(&mut self) -> io::Result<()> {
+            disable_raw_mode()?;
+            execute!(
+                io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                cursor::Show
+            )
+        }
+    }
+}
+
+#[cfg(feature = "termion")]
+mod termion_backend {
+    use super::{Key, MouseInfo, MouseKind, TermBackend, TermEvent};
+    use std::io::{self, Write};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+    use termion::event::{Event as TEvent, Key as TKey, MouseButton as TMouseButton, MouseEvent as TMouseEvent};
+    use termion::input::{MouseTerminal, TermRead};
+    use termion::raw::{IntoRawMode, RawTerminal};
+
+    pub struct TermionTermBackend {
+        events: mpsc::Receiver<TEvent>,
+        // Held only for its Drop impl, which disables mouse reporting and
+        // restores the terminal mode.
+        raw_mode: Option<MouseTerminal<RawTerminal<io::Stdout>>>,
+    }
+
+    impl TermBackend for TermionTermBackend {
+        fn init() -> io::Result<Self> {
+            let raw_mode = MouseTerminal::from(io::stdout().into_raw_mode()?);
+            write!(io::stdout(), "{}", termion::screen::ToAlternateScreen)?;
+            io::stdout().flush()?;
+
+            let (tx, events) = mpsc::channel();
+            thread::spawn(move || {
+                for event in io::stdin().events().flatten() {
+                    if tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            });
+            Ok(TermionTermBackend {
+                events,
+                raw_mode: Some(raw_mode),
+            })
+        }
+
+        fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<TermEvent>> {
+            match self.events.recv_timeout(timeout) {
+                Ok(TEvent::Key(key)) => Ok(Some(TermEvent::Key(match key {
+                    TKey::Char('\n') => Key::Enter,
+                    TKey::Char('\t') => Key::Tab,
+                    TKey::Char(c) => Key::Char(c),
+                    TKey::Backspace => Key::Backspace,
+                    TKey::Esc => Key::Esc,
+                    TKey::Left => Key::Left,
+                    TKey::Right => Key::Right,
+                    TKey::Up => Key::Up,
+                    TKey::Down => Key::Down,
+                    TKey::BackTab => Key::BackTab,
+                    _ => Key::Other,
+                }))),
+                Ok(TEvent::Mouse(TMouseEvent::Press(button, column, row))) => {
+                    Ok(Some(TermEvent::Mouse(MouseInfo {
+                        kind: match button {
+                            TMouseButton::Left => MouseKind::LeftDown,
+                            TMouseButton::WheelUp => MouseKind::ScrollUp,
+                            TMouseButton::WheelDown => MouseKind::ScrollDown,
+                            _ => MouseKind::Other,
+                        },
+                        column,
+                        row,
+                    })))
+                }
+                Ok(_) => Ok(Some(TermEvent::Tick)),
+                Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+                Err(mpsc::RecvTimeoutError::Disconnected) => Ok(None),
+            }
+        }
+
+        fn restore(&mut self) -> io::Result<()> {
+            write!(io::stdout(), "{}", termion::screen::ToMainScreen)?;
+            io::stdout().flush()?;
+            self.raw_mode.take();
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "crossterm")]
+pub use crossterm_backend::CrosstermTermBackend as ActiveTermBackend;
+
+#[cfg(all(feature = "termion", not(feature = "crossterm")))]
+pub use termion_backend::TermionTermBackend as ActiveTermBackend;